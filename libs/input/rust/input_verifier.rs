@@ -18,21 +18,41 @@
 
 use crate::ffi::RustPointerProperties;
 use crate::input::{DeviceId, MotionAction, MotionFlags};
-use log::info;
+use log::{error, info};
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+/// Coordinate jump (in pixels, on a single axis) beyond which a MOVE is treated as an impossible
+/// teleport when the verifier is running in strict mode. The default is deliberately generous so
+/// that legitimate fast flings are never flagged; callers that want a tighter bound can override it
+/// with [`InputVerifier::set_move_delta_threshold`].
+const DEFAULT_MOVE_DELTA_THRESHOLD: f32 = 10_000.0;
+
 /// The InputVerifier is used to validate a stream of input events.
 pub struct InputVerifier {
     name: String,
     should_log: bool,
+    strict: bool,
+    move_delta_threshold: f32,
     touching_pointer_ids_by_device: HashMap<DeviceId, HashSet<i32>>,
     hovering_pointer_ids_by_device: HashMap<DeviceId, HashSet<i32>>,
+    last_coords_by_device: HashMap<DeviceId, HashMap<i32, (f32, f32)>>,
+    // Per active gesture, the down_time recorded at its initial DOWN/HOVER_ENTER and the largest
+    // event_time seen so far. Used to reject reordered or spliced event streams.
+    gesture_timestamps_by_device: HashMap<DeviceId, GestureTimestamps>,
+}
+
+/// The timestamps that identify and order a single gesture, in nanoseconds.
+struct GestureTimestamps {
+    down_time: i64,
+    last_event_time: i64,
 }
 
 impl InputVerifier {
-    /// Create a new InputVerifier.
-    pub fn new(name: &str, should_log: bool) -> Self {
+    /// Create a new InputVerifier. When `strict` is set, the verifier additionally validates
+    /// per-pointer coordinates, flagging MOVEs that teleport beyond the configured delta threshold
+    /// or that carry NaN/infinite coordinates.
+    pub fn new(name: &str, should_log: bool, strict: bool) -> Self {
         logger::init(
             logger::Config::default()
                 .with_tag_on_device("InputVerifier")
@@ -41,20 +61,38 @@ impl InputVerifier {
         Self {
             name: name.to_owned(),
             should_log,
+            strict,
+            move_delta_threshold: DEFAULT_MOVE_DELTA_THRESHOLD,
             touching_pointer_ids_by_device: HashMap::new(),
             hovering_pointer_ids_by_device: HashMap::new(),
+            last_coords_by_device: HashMap::new(),
+            gesture_timestamps_by_device: HashMap::new(),
         }
     }
 
+    /// Override the per-pointer delta threshold, in pixels, used by strict mode to detect
+    /// teleporting MOVEs. Has no effect unless the verifier was created with `strict` set.
+    pub fn set_move_delta_threshold(&mut self, threshold: f32) {
+        self.move_delta_threshold = threshold;
+    }
+
     /// Process a pointer movement event from an InputDevice.
-    /// If the event is not valid, we return an error string that describes the issue.
+    /// If the event is not valid, we return a [`VerificationError`] that describes which rule was
+    /// violated; call [`VerificationError::kind`] on it to get an FFI-safe, payload-free
+    /// [`VerificationErrorKind`] that callers - including the C++ `InputVerifier` across the FFI
+    /// boundary, once it's wired to call `kind()` - can use to bump a per-violation counter rather
+    /// than only logging a string. The violation is also logged as `"{name}: {message}"`, prefixed
+    /// with this verifier's `name` - this matters because multiple `InputVerifier` instances (one
+    /// per device) can share a log.
     pub fn process_movement(
         &mut self,
         device_id: DeviceId,
         action: u32,
         pointer_properties: &[RustPointerProperties],
         flags: MotionFlags,
-    ) -> Result<(), String> {
+        event_time: i64,
+        down_time: i64,
+    ) -> Result<(), VerificationError> {
         if self.should_log {
             info!(
                 "Processing {} for device {:?} ({} pointer{}) on {}",
@@ -66,150 +104,261 @@ impl InputVerifier {
             );
         }
 
-        match action.into() {
+        let result = self.process_movement_inner(
+            device_id,
+            action,
+            pointer_properties,
+            flags,
+            event_time,
+            down_time,
+        );
+        if let Err(ref e) = result {
+            error!("{}: {}", self.name, e);
+        }
+        result
+    }
+
+    fn process_movement_inner(
+        &mut self,
+        device_id: DeviceId,
+        action: u32,
+        pointer_properties: &[RustPointerProperties],
+        flags: MotionFlags,
+        event_time: i64,
+        down_time: i64,
+    ) -> Result<(), VerificationError> {
+        self.check_timestamps(device_id, event_time, down_time)?;
+
+        let motion_action: MotionAction = action.into();
+        match motion_action {
             MotionAction::Down => {
+                if self.is_hovering(device_id) {
+                    return Err(VerificationError::TouchHoverConflict {
+                        device_id,
+                        pointer_ids: sorted_ids(&self.hovering_pointer_ids_by_device[&device_id]),
+                    });
+                }
+                let pointer_id = first_pointer_id(pointer_properties)?;
                 let it = self
                     .touching_pointer_ids_by_device
                     .entry(device_id)
                     .or_insert_with(HashSet::new);
-                let pointer_id = pointer_properties[0].id;
                 if it.contains(&pointer_id) {
-                    return Err(format!(
-                        "{}: Invalid DOWN event - pointers already down for device {:?}: {:?}",
-                        self.name, device_id, it
-                    ));
+                    return Err(VerificationError::AlreadyDown { device_id, pointer_id });
                 }
                 it.insert(pointer_id);
+                self.record_pointer_coords(device_id, &pointer_properties[0])?;
             }
             MotionAction::PointerDown { action_index } => {
+                if self.is_hovering(device_id) {
+                    return Err(VerificationError::TouchHoverConflict {
+                        device_id,
+                        pointer_ids: sorted_ids(&self.hovering_pointer_ids_by_device[&device_id]),
+                    });
+                }
                 if !self.touching_pointer_ids_by_device.contains_key(&device_id) {
-                    return Err(format!(
-                        "{}: Received POINTER_DOWN but no pointers are currently down \
-                        for device {:?}",
-                        self.name, device_id
-                    ));
+                    return Err(VerificationError::NoPointersDown { device_id });
                 }
+                let pointer_id = pointer_id_at(pointer_properties, action_index)?;
                 let it = self.touching_pointer_ids_by_device.get_mut(&device_id).unwrap();
-                let pointer_id = pointer_properties[action_index].id;
                 if it.contains(&pointer_id) {
-                    return Err(format!(
-                        "{}: Pointer with id={} not found in the properties",
-                        self.name, pointer_id
-                    ));
+                    return Err(VerificationError::AlreadyDown { device_id, pointer_id });
                 }
                 it.insert(pointer_id);
+                self.record_pointer_coords(device_id, &pointer_properties[action_index])?;
             }
             MotionAction::Move => {
                 if !self.ensure_touching_pointers_match(device_id, pointer_properties) {
-                    return Err(format!(
-                        "{}: ACTION_MOVE touching pointers don't match",
-                        self.name
-                    ));
+                    return Err(VerificationError::PointersDontMatch {
+                        device_id,
+                        pointer_ids: touching_ids(&self.touching_pointer_ids_by_device, device_id),
+                    });
+                }
+                for pointer_property in pointer_properties.iter() {
+                    self.update_pointer_coords(device_id, pointer_property)?;
                 }
             }
             MotionAction::PointerUp { action_index } => {
                 if !self.touching_pointer_ids_by_device.contains_key(&device_id) {
-                    return Err(format!(
-                        "{}: Received POINTER_UP but no pointers are currently down for device \
-                        {:?}",
-                        self.name, device_id
-                    ));
+                    return Err(VerificationError::NoPointersDown { device_id });
                 }
+                let pointer_id = pointer_id_at(pointer_properties, action_index)?;
                 let it = self.touching_pointer_ids_by_device.get_mut(&device_id).unwrap();
-                let pointer_id = pointer_properties[action_index].id;
                 it.remove(&pointer_id);
+                if let Some(coords) = self.last_coords_by_device.get_mut(&device_id) {
+                    coords.remove(&pointer_id);
+                }
             }
             MotionAction::Up => {
                 if !self.touching_pointer_ids_by_device.contains_key(&device_id) {
-                    return Err(format!(
-                        "{} Received ACTION_UP but no pointers are currently down for device {:?}",
-                        self.name, device_id
-                    ));
+                    return Err(VerificationError::StaleUp { device_id });
                 }
+                let pointer_id = first_pointer_id(pointer_properties)?;
                 let it = self.touching_pointer_ids_by_device.get_mut(&device_id).unwrap();
                 if it.len() != 1 {
-                    return Err(format!(
-                        "{}: Got ACTION_UP, but we have pointers: {:?} for device {:?}",
-                        self.name, it, device_id
-                    ));
+                    return Err(VerificationError::TooManyPointers {
+                        device_id,
+                        pointer_ids: sorted_ids(it),
+                    });
                 }
-                let pointer_id = pointer_properties[0].id;
                 if !it.contains(&pointer_id) {
-                    return Err(format!(
-                        "{}: Got ACTION_UP, but pointerId {} is not touching. Touching pointers:\
-                        {:?} for device {:?}",
-                        self.name, pointer_id, it, device_id
-                    ));
+                    return Err(VerificationError::PointerNotDown { device_id, pointer_id });
                 }
                 self.touching_pointer_ids_by_device.remove(&device_id);
+                self.last_coords_by_device.remove(&device_id);
             }
             MotionAction::Cancel => {
                 if !flags.contains(MotionFlags::CANCELED) {
-                    return Err(format!(
-                        "{}: For ACTION_CANCEL, must set FLAG_CANCELED",
-                        self.name
-                    ));
+                    return Err(VerificationError::MissingCancelFlag { device_id });
                 }
                 if !self.ensure_touching_pointers_match(device_id, pointer_properties) {
-                    return Err(format!(
-                        "{}: Got ACTION_CANCEL, but the pointers don't match. \
-                        Existing pointers: {:?}",
-                        self.name, self.touching_pointer_ids_by_device
-                    ));
+                    return Err(VerificationError::PointersDontMatch {
+                        device_id,
+                        pointer_ids: touching_ids(&self.touching_pointer_ids_by_device, device_id),
+                    });
                 }
                 self.touching_pointer_ids_by_device.remove(&device_id);
+                self.last_coords_by_device.remove(&device_id);
             }
             /*
-             * The hovering protocol currently supports a single pointer only, because we do not
-             * have ACTION_HOVER_POINTER_ENTER or ACTION_HOVER_POINTER_EXIT.
-             * Still, we are keeping the infrastructure here pretty general in case that is
-             * eventually supported.
+             * The hovering protocol supports multiple simultaneous pointers. HOVER_ENTER seeds the
+             * first hovering pointer, HOVER_POINTER_ENTER/EXIT add and remove the additional ones
+             * (mirroring POINTER_DOWN/POINTER_UP for touch), and HOVER_EXIT terminates the last
+             * one. This lets the verifier validate multi-stylus/multi-finger hover streams from
+             * newer digitizers instead of erroring on the second hovering pointer.
              */
             MotionAction::HoverEnter => {
-                if self.hovering_pointer_ids_by_device.contains_key(&device_id) {
-                    return Err(format!(
-                        "{}: Invalid HOVER_ENTER event - pointers already hovering for device {:?}:\
-                        {:?}",
-                        self.name, device_id, self.hovering_pointer_ids_by_device
-                    ));
+                if self.is_touching(device_id) {
+                    return Err(VerificationError::TouchHoverConflict {
+                        device_id,
+                        pointer_ids: touching_ids(&self.touching_pointer_ids_by_device, device_id),
+                    });
                 }
+                if let Some(it) = self.hovering_pointer_ids_by_device.get(&device_id) {
+                    return Err(VerificationError::HoverAlreadyActive {
+                        device_id,
+                        pointer_ids: sorted_ids(it),
+                    });
+                }
+                let pointer_id = first_pointer_id(pointer_properties)?;
                 let it = self
                     .hovering_pointer_ids_by_device
                     .entry(device_id)
                     .or_insert_with(HashSet::new);
-                it.insert(pointer_properties[0].id);
+                it.insert(pointer_id);
             }
             MotionAction::HoverMove => {
+                // A device cannot hover and touch at once, so a HOVER_MOVE while pointers are
+                // touching is a conflict (mirroring the HOVER_ENTER check above).
+                if self.is_touching(device_id) {
+                    return Err(VerificationError::TouchHoverConflict {
+                        device_id,
+                        pointer_ids: touching_ids(&self.touching_pointer_ids_by_device, device_id),
+                    });
+                }
                 // For compatibility reasons, we allow HOVER_MOVE without a prior HOVER_ENTER.
                 // If there was no prior HOVER_ENTER, just start a new hovering pointer.
+                let pointer_id = first_pointer_id(pointer_properties)?;
                 let it = self
                     .hovering_pointer_ids_by_device
                     .entry(device_id)
                     .or_insert_with(HashSet::new);
-                it.insert(pointer_properties[0].id);
+                it.insert(pointer_id);
             }
             MotionAction::HoverExit => {
                 if !self.hovering_pointer_ids_by_device.contains_key(&device_id) {
-                    return Err(format!(
-                        "{}: Invalid HOVER_EXIT event - no pointers are hovering for device {:?}",
-                        self.name, device_id
-                    ));
+                    return Err(VerificationError::NotHovering { device_id });
                 }
-                let pointer_id = pointer_properties[0].id;
+                let pointer_id = first_pointer_id(pointer_properties)?;
                 let it = self.hovering_pointer_ids_by_device.get_mut(&device_id).unwrap();
                 it.remove(&pointer_id);
 
                 if !it.is_empty() {
-                    return Err(format!(
-                        "{}: Removed hovering pointer {}, but pointers are still\
-                               hovering for device {:?}: {:?}",
-                        self.name, pointer_id, device_id, it
-                    ));
+                    return Err(VerificationError::HoverPointersRemaining {
+                        device_id,
+                        pointer_ids: sorted_ids(it),
+                    });
                 }
                 self.hovering_pointer_ids_by_device.remove(&device_id);
             }
+            MotionAction::HoverPointerEnter { action_index } => {
+                if !self.hovering_pointer_ids_by_device.contains_key(&device_id) {
+                    return Err(VerificationError::NotHovering { device_id });
+                }
+                let pointer_id = pointer_id_at(pointer_properties, action_index)?;
+                let it = self.hovering_pointer_ids_by_device.get_mut(&device_id).unwrap();
+                if it.contains(&pointer_id) {
+                    return Err(VerificationError::HoverAlreadyActive {
+                        device_id,
+                        pointer_ids: vec![pointer_id],
+                    });
+                }
+                it.insert(pointer_id);
+            }
+            MotionAction::HoverPointerExit { action_index } => {
+                if !self.hovering_pointer_ids_by_device.contains_key(&device_id) {
+                    return Err(VerificationError::NotHovering { device_id });
+                }
+                let pointer_id = pointer_id_at(pointer_properties, action_index)?;
+                let it = self.hovering_pointer_ids_by_device.get_mut(&device_id).unwrap();
+                // HOVER_POINTER_EXIT removes an additional hovering pointer; the final one must
+                // leave via HOVER_EXIT. Check before mutating so a rejected event leaves the
+                // hovering set untouched.
+                if it.len() <= 1 {
+                    return Err(VerificationError::LastHoverPointer {
+                        device_id,
+                        pointer_ids: sorted_ids(it),
+                    });
+                }
+                it.remove(&pointer_id);
+            }
             _ => return Ok(()),
         }
+
+        // The event was accepted; update the gesture timestamps. A terminal transition clears them,
+        // a gesture-starting transition records them, and everything in between advances the last
+        // seen event_time.
+        match motion_action {
+            MotionAction::Down | MotionAction::HoverEnter => {
+                self.gesture_timestamps_by_device
+                    .insert(device_id, GestureTimestamps { down_time, last_event_time: event_time });
+            }
+            MotionAction::Up | MotionAction::Cancel | MotionAction::HoverExit => {
+                self.gesture_timestamps_by_device.remove(&device_id);
+            }
+            _ => {
+                if let Some(timestamps) = self.gesture_timestamps_by_device.get_mut(&device_id) {
+                    timestamps.last_event_time = event_time;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the monotonicity of `event_time` and the stability of `down_time` against the
+    /// timestamps recorded for the device's in-progress gesture, if any.
+    fn check_timestamps(
+        &self,
+        device_id: DeviceId,
+        event_time: i64,
+        down_time: i64,
+    ) -> Result<(), VerificationError> {
+        if let Some(timestamps) = self.gesture_timestamps_by_device.get(&device_id) {
+            if down_time != timestamps.down_time {
+                return Err(VerificationError::DownTimeChanged {
+                    device_id,
+                    expected: timestamps.down_time,
+                    received: down_time,
+                });
+            }
+            if event_time < timestamps.last_event_time {
+                return Err(VerificationError::EventTimeDecreased {
+                    device_id,
+                    previous: timestamps.last_event_time,
+                    received: event_time,
+                });
+            }
+        }
         Ok(())
     }
 
@@ -219,6 +368,88 @@ impl InputVerifier {
     pub fn reset_device(&mut self, device_id: DeviceId) {
         self.touching_pointer_ids_by_device.remove(&device_id);
         self.hovering_pointer_ids_by_device.remove(&device_id);
+        self.last_coords_by_device.remove(&device_id);
+        self.gesture_timestamps_by_device.remove(&device_id);
+    }
+
+    /// Record the initial position of a newly-touching pointer, rejecting non-finite coordinates in
+    /// strict mode.
+    fn record_pointer_coords(
+        &mut self,
+        device_id: DeviceId,
+        pointer_property: &RustPointerProperties,
+    ) -> Result<(), VerificationError> {
+        if self.strict {
+            self.ensure_finite(device_id, pointer_property)?;
+        }
+        self.last_coords_by_device
+            .entry(device_id)
+            .or_insert_with(HashMap::new)
+            .insert(pointer_property.id, (pointer_property.x, pointer_property.y));
+        Ok(())
+    }
+
+    /// Update the last-known position of a touching pointer as it moves. In strict mode, a MOVE that
+    /// reports non-finite coordinates or that jumps further than the configured delta threshold from
+    /// the pointer's previous position is treated as a verification error.
+    fn update_pointer_coords(
+        &mut self,
+        device_id: DeviceId,
+        pointer_property: &RustPointerProperties,
+    ) -> Result<(), VerificationError> {
+        if self.strict {
+            self.ensure_finite(device_id, pointer_property)?;
+            if let Some((last_x, last_y)) = self
+                .last_coords_by_device
+                .get(&device_id)
+                .and_then(|coords| coords.get(&pointer_property.id))
+            {
+                let dx = (pointer_property.x - last_x).abs();
+                let dy = (pointer_property.y - last_y).abs();
+                if dx > self.move_delta_threshold || dy > self.move_delta_threshold {
+                    return Err(VerificationError::Teleport {
+                        device_id,
+                        pointer_id: pointer_property.id,
+                    });
+                }
+            }
+        }
+        self.last_coords_by_device
+            .entry(device_id)
+            .or_insert_with(HashMap::new)
+            .insert(pointer_property.id, (pointer_property.x, pointer_property.y));
+        Ok(())
+    }
+
+    fn ensure_finite(
+        &self,
+        device_id: DeviceId,
+        pointer_property: &RustPointerProperties,
+    ) -> Result<(), VerificationError> {
+        if !pointer_property.x.is_finite() || !pointer_property.y.is_finite() {
+            return Err(VerificationError::NonFiniteCoordinates {
+                device_id,
+                pointer_id: pointer_property.id,
+            });
+        }
+        Ok(())
+    }
+
+    /// Whether the device currently has any touching pointers. A device cannot be touching and
+    /// hovering at the same time, so DOWN/POINTER_DOWN and HOVER_ENTER consult these before
+    /// starting a new gesture.
+    fn is_touching(&self, device_id: DeviceId) -> bool {
+        self.touching_pointer_ids_by_device.get(&device_id).is_some_and(|it| !it.is_empty())
+    }
+
+    fn is_hovering(&self, device_id: DeviceId) -> bool {
+        self.hovering_pointer_ids_by_device.get(&device_id).is_some_and(|it| !it.is_empty())
+    }
+
+    /// Whether the device has no touching or hovering pointers tracked. After an accepted
+    /// terminal transition (UP/CANCEL/HOVER_EXIT) this must hold, which fuzzers and tests assert.
+    pub fn is_device_clear(&self, device_id: DeviceId) -> bool {
+        !self.is_touching(device_id) && !self.is_hovering(device_id)
     }
 
     fn ensure_touching_pointers_match(
@@ -240,22 +471,246 @@ impl InputVerifier {
     }
 }
 
+/// A specific input-verification rule that an event stream violated. Each variant carries the
+/// detail of the violation for callers that want it in-process: the device and, where relevant,
+/// the pointer ids or timestamps involved (`InvalidActionIndex` carries the action index and the
+/// pointer count instead of a device). To map a
+/// violation to a counter across the FFI boundary, use [`VerificationError::kind`] instead of this
+/// type directly: `VerificationError` itself is not `#[repr(C)]`/cxx-shareable, since its variants
+/// carry `Vec<i32>` payloads that don't have a stable FFI layout.
+/// `Display` renders a human-readable message for each violation; its wording is not identical to
+/// the string errors `process_movement` returned before this type existed, and it has no
+/// per-instance `name` prefix (a `VerificationError` has no verifier to read it from); callers
+/// that want that prefix get it from [`InputVerifier::process_movement`]'s own logging instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerificationError {
+    /// A DOWN or POINTER_DOWN arrived for a pointer that is already touching.
+    AlreadyDown { device_id: DeviceId, pointer_id: i32 },
+    /// An event referenced a pointer that is not currently touching.
+    PointerNotDown { device_id: DeviceId, pointer_id: i32 },
+    /// A POINTER_DOWN/POINTER_UP arrived while no pointers were down for the device.
+    NoPointersDown { device_id: DeviceId },
+    /// An ACTION_UP arrived while more than one pointer was still down.
+    TooManyPointers { device_id: DeviceId, pointer_ids: Vec<i32> },
+    /// The pointers reported by a MOVE or CANCEL do not match the ones currently down.
+    PointersDontMatch { device_id: DeviceId, pointer_ids: Vec<i32> },
+    /// An ACTION_CANCEL was received without FLAG_CANCELED set.
+    MissingCancelFlag { device_id: DeviceId },
+    /// A HOVER_ENTER arrived while pointers were already hovering for the device, or a
+    /// HOVER_POINTER_ENTER arrived for a pointer that was already hovering.
+    HoverAlreadyActive { device_id: DeviceId, pointer_ids: Vec<i32> },
+    /// A hover event arrived while no pointers were hovering for the device.
+    NotHovering { device_id: DeviceId },
+    /// Hovering pointers remained after a HOVER_EXIT.
+    HoverPointersRemaining { device_id: DeviceId, pointer_ids: Vec<i32> },
+    /// A HOVER_POINTER_EXIT tried to remove the last hovering pointer, which must leave via
+    /// HOVER_EXIT instead.
+    LastHoverPointer { device_id: DeviceId, pointer_ids: Vec<i32> },
+    /// A device was asked to touch and hover at the same time.
+    TouchHoverConflict { device_id: DeviceId, pointer_ids: Vec<i32> },
+    /// An ACTION_UP arrived for a device with no pointers down (a stale or duplicated UP).
+    StaleUp { device_id: DeviceId },
+    /// An event's `action_index` was out of bounds for the reported pointer count.
+    InvalidActionIndex { action_index: usize, pointer_count: usize },
+    /// A pointer reported NaN or infinite coordinates (strict mode only).
+    NonFiniteCoordinates { device_id: DeviceId, pointer_id: i32 },
+    /// A pointer jumped further than the configured delta threshold in a single MOVE (strict mode).
+    Teleport { device_id: DeviceId, pointer_id: i32 },
+    /// An event's `event_time` went backwards within a gesture.
+    EventTimeDecreased { device_id: DeviceId, previous: i64, received: i64 },
+    /// An event's `down_time` did not match the one recorded at the start of the gesture.
+    DownTimeChanged { device_id: DeviceId, expected: i64, received: i64 },
+}
+
+impl VerificationError {
+    /// The FFI-safe kind of this violation. Unlike `VerificationError` itself, `VerificationErrorKind`
+    /// carries no per-violation payload, so it can cross into C++ (e.g. via a cxx shared enum) and be
+    /// used to index a per-violation-kind counter, which is the whole point of returning a structured
+    /// error from [`InputVerifier::process_movement`] instead of a log string.
+    pub fn kind(&self) -> VerificationErrorKind {
+        match self {
+            Self::AlreadyDown { .. } => VerificationErrorKind::AlreadyDown,
+            Self::PointerNotDown { .. } => VerificationErrorKind::PointerNotDown,
+            Self::NoPointersDown { .. } => VerificationErrorKind::NoPointersDown,
+            Self::TooManyPointers { .. } => VerificationErrorKind::TooManyPointers,
+            Self::PointersDontMatch { .. } => VerificationErrorKind::PointersDontMatch,
+            Self::MissingCancelFlag { .. } => VerificationErrorKind::MissingCancelFlag,
+            Self::HoverAlreadyActive { .. } => VerificationErrorKind::HoverAlreadyActive,
+            Self::NotHovering { .. } => VerificationErrorKind::NotHovering,
+            Self::HoverPointersRemaining { .. } => VerificationErrorKind::HoverPointersRemaining,
+            Self::LastHoverPointer { .. } => VerificationErrorKind::LastHoverPointer,
+            Self::TouchHoverConflict { .. } => VerificationErrorKind::TouchHoverConflict,
+            Self::StaleUp { .. } => VerificationErrorKind::StaleUp,
+            Self::InvalidActionIndex { .. } => VerificationErrorKind::InvalidActionIndex,
+            Self::NonFiniteCoordinates { .. } => VerificationErrorKind::NonFiniteCoordinates,
+            Self::Teleport { .. } => VerificationErrorKind::Teleport,
+            Self::EventTimeDecreased { .. } => VerificationErrorKind::EventTimeDecreased,
+            Self::DownTimeChanged { .. } => VerificationErrorKind::DownTimeChanged,
+        }
+    }
+}
+
+/// The FFI-safe, payload-free discriminant of a [`VerificationError`]. This is the type that
+/// should actually cross the FFI boundary (e.g. as a cxx shared enum) so the C++ `InputVerifier`
+/// can bump a counter per violation kind instead of parsing a log string.
+///
+/// The numeric values are part of the FFI contract: they must not be reordered or reused, and new
+/// violations are appended at the end.
+///
+/// NOTE: wiring this into the native `InputVerifier` (the cxx bridge declaration and the C++
+/// call site) is outside this crate's snapshot and is not done by this change; this type is the
+/// Rust-side half of that contract, ready for the native side to bind.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationErrorKind {
+    AlreadyDown = 0,
+    PointerNotDown = 1,
+    NoPointersDown = 2,
+    TooManyPointers = 3,
+    PointersDontMatch = 4,
+    MissingCancelFlag = 5,
+    HoverAlreadyActive = 6,
+    NotHovering = 7,
+    HoverPointersRemaining = 8,
+    LastHoverPointer = 9,
+    TouchHoverConflict = 10,
+    StaleUp = 11,
+    InvalidActionIndex = 12,
+    NonFiniteCoordinates = 13,
+    Teleport = 14,
+    EventTimeDecreased = 15,
+    DownTimeChanged = 16,
+}
+
+impl std::fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyDown { device_id, pointer_id } => write!(
+                f,
+                "Invalid DOWN event - pointer {pointer_id} already down for device {device_id:?}"
+            ),
+            Self::PointerNotDown { device_id, pointer_id } => {
+                write!(f, "Pointer {pointer_id} is not touching for device {device_id:?}")
+            }
+            Self::NoPointersDown { device_id } => {
+                write!(f, "No pointers are currently down for device {device_id:?}")
+            }
+            Self::TooManyPointers { device_id, pointer_ids } => write!(
+                f,
+                "Got ACTION_UP, but we have pointers: {pointer_ids:?} for device {device_id:?}"
+            ),
+            Self::PointersDontMatch { device_id, pointer_ids } => write!(
+                f,
+                "Pointers don't match. Existing pointers: {pointer_ids:?} for device {device_id:?}"
+            ),
+            Self::MissingCancelFlag { device_id } => write!(
+                f,
+                "For ACTION_CANCEL, must set FLAG_CANCELED (device {device_id:?})"
+            ),
+            Self::HoverAlreadyActive { device_id, pointer_ids } => write!(
+                f,
+                "Invalid hover event - pointers already hovering for device {device_id:?}: \
+                {pointer_ids:?}"
+            ),
+            Self::NotHovering { device_id } => {
+                write!(f, "No pointers are hovering for device {device_id:?}")
+            }
+            Self::HoverPointersRemaining { device_id, pointer_ids } => write!(
+                f,
+                "Hovering pointers still present for device {device_id:?}: {pointer_ids:?}"
+            ),
+            Self::LastHoverPointer { device_id, pointer_ids } => write!(
+                f,
+                "Cannot HOVER_POINTER_EXIT the last hovering pointer for device {device_id:?} \
+                (use HOVER_EXIT): {pointer_ids:?}"
+            ),
+            Self::TouchHoverConflict { device_id, pointer_ids } => write!(
+                f,
+                "Device {device_id:?} cannot touch and hover at once (conflicting pointers: \
+                {pointer_ids:?})"
+            ),
+            Self::StaleUp { device_id } => write!(
+                f,
+                "Received ACTION_UP but no pointers are currently down for device {device_id:?}"
+            ),
+            Self::InvalidActionIndex { action_index, pointer_count } => write!(
+                f,
+                "action_index {action_index} is out of bounds for {pointer_count} pointer(s)"
+            ),
+            Self::NonFiniteCoordinates { device_id, pointer_id } => write!(
+                f,
+                "Pointer {pointer_id} has non-finite coordinates for device {device_id:?}"
+            ),
+            Self::Teleport { device_id, pointer_id } => {
+                write!(f, "Pointer {pointer_id} teleported for device {device_id:?}")
+            }
+            Self::EventTimeDecreased { device_id, previous, received } => write!(
+                f,
+                "event_time went backwards for device {device_id:?}: {received} < {previous}"
+            ),
+            Self::DownTimeChanged { device_id, expected, received } => write!(
+                f,
+                "down_time changed mid-gesture for device {device_id:?}: expected {expected}, got \
+                {received}"
+            ),
+        }
+    }
+}
+
+/// Return the id of the pointer at `action_index`, or an error if the properties slice does not
+/// have an entry at that index. Malformed events (e.g. an `action_index` that exceeds the reported
+/// pointer count) must be rejected rather than indexing out of bounds.
+fn pointer_id_at(
+    pointer_properties: &[RustPointerProperties],
+    action_index: usize,
+) -> Result<i32, VerificationError> {
+    match pointer_properties.get(action_index) {
+        Some(properties) => Ok(properties.id),
+        None => Err(VerificationError::InvalidActionIndex {
+            action_index,
+            pointer_count: pointer_properties.len(),
+        }),
+    }
+}
+
+/// Return the id of the first pointer, or an error if the properties slice is empty.
+fn first_pointer_id(pointer_properties: &[RustPointerProperties]) -> Result<i32, VerificationError> {
+    pointer_id_at(pointer_properties, 0)
+}
+
+/// Return the ids in a pointer set as a sorted vector, so violation reports are deterministic.
+fn sorted_ids(pointers: &HashSet<i32>) -> Vec<i32> {
+    let mut ids: Vec<i32> = pointers.iter().copied().collect();
+    ids.sort_unstable();
+    ids
+}
+
+/// Return the sorted touching pointer ids for a device, or an empty vector if it has none.
+fn touching_ids(
+    touching_pointer_ids_by_device: &HashMap<DeviceId, HashSet<i32>>,
+    device_id: DeviceId,
+) -> Vec<i32> {
+    touching_pointer_ids_by_device.get(&device_id).map(sorted_ids).unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::input_verifier::InputVerifier;
+    use crate::input_verifier::{InputVerifier, VerificationErrorKind};
     use crate::DeviceId;
     use crate::MotionFlags;
     use crate::RustPointerProperties;
     #[test]
     fn single_pointer_stream() {
-        let mut verifier = InputVerifier::new("Test", /*should_log*/ false);
-        let pointer_properties = Vec::from([RustPointerProperties { id: 0 }]);
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties = Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
         assert!(verifier
             .process_movement(
                 DeviceId(1),
                 input_bindgen::AMOTION_EVENT_ACTION_DOWN,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
         assert!(verifier
@@ -264,6 +719,8 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_MOVE,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
         assert!(verifier
@@ -272,20 +729,24 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_UP,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
     }
 
     #[test]
     fn multi_device_stream() {
-        let mut verifier = InputVerifier::new("Test", /*should_log*/ false);
-        let pointer_properties = Vec::from([RustPointerProperties { id: 0 }]);
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties = Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
         assert!(verifier
             .process_movement(
                 DeviceId(1),
                 input_bindgen::AMOTION_EVENT_ACTION_DOWN,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
         assert!(verifier
@@ -294,6 +755,8 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_MOVE,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
         assert!(verifier
@@ -302,6 +765,8 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_DOWN,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
         assert!(verifier
@@ -310,6 +775,8 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_MOVE,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
         assert!(verifier
@@ -318,20 +785,24 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_UP,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
     }
 
     #[test]
     fn action_cancel() {
-        let mut verifier = InputVerifier::new("Test", /*should_log*/ false);
-        let pointer_properties = Vec::from([RustPointerProperties { id: 0 }]);
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties = Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
         assert!(verifier
             .process_movement(
                 DeviceId(1),
                 input_bindgen::AMOTION_EVENT_ACTION_DOWN,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
         assert!(verifier
@@ -340,20 +811,24 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_CANCEL,
                 &pointer_properties,
                 MotionFlags::CANCELED,
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
     }
 
     #[test]
     fn invalid_action_cancel() {
-        let mut verifier = InputVerifier::new("Test", /*should_log*/ false);
-        let pointer_properties = Vec::from([RustPointerProperties { id: 0 }]);
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties = Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
         assert!(verifier
             .process_movement(
                 DeviceId(1),
                 input_bindgen::AMOTION_EVENT_ACTION_DOWN,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
         assert!(verifier
@@ -362,34 +837,40 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_CANCEL,
                 &pointer_properties,
                 MotionFlags::empty(), // forgot to set FLAG_CANCELED
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_err());
     }
 
     #[test]
     fn invalid_up() {
-        let mut verifier = InputVerifier::new("Test", /*should_log*/ false);
-        let pointer_properties = Vec::from([RustPointerProperties { id: 0 }]);
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties = Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
         assert!(verifier
             .process_movement(
                 DeviceId(1),
                 input_bindgen::AMOTION_EVENT_ACTION_UP,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_err());
     }
 
     #[test]
     fn correct_hover_sequence() {
-        let mut verifier = InputVerifier::new("Test", /*should_log*/ false);
-        let pointer_properties = Vec::from([RustPointerProperties { id: 0 }]);
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties = Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
         assert!(verifier
             .process_movement(
                 DeviceId(1),
                 input_bindgen::AMOTION_EVENT_ACTION_HOVER_ENTER,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
 
@@ -399,6 +880,8 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_HOVER_MOVE,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
 
@@ -408,6 +891,8 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_HOVER_EXIT,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
 
@@ -417,20 +902,92 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_HOVER_ENTER,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn multi_pointer_hover_sequence() {
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer0 = Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
+        let pointer1 = Vec::from([RustPointerProperties { id: 1, x: 0.0, y: 0.0, tool_type: 0 }]);
+        let both_pointers = Vec::from([
+            RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 },
+            RustPointerProperties { id: 1, x: 0.0, y: 0.0, tool_type: 0 },
+        ]);
+
+        // Pointer 0 starts the hovering gesture.
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_HOVER_ENTER,
+                &pointer0,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_ok());
+
+        // Pointer 1 joins as an additional hovering pointer.
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                pointer_action(input_bindgen::AMOTION_EVENT_ACTION_HOVER_POINTER_ENTER, 1),
+                &both_pointers,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_ok());
+
+        // Pointer 0 leaves while pointer 1 is still hovering.
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                pointer_action(input_bindgen::AMOTION_EVENT_ACTION_HOVER_POINTER_EXIT, 0),
+                &both_pointers,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_ok());
+
+        // The last hovering pointer must leave via HOVER_EXIT.
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_HOVER_EXIT,
+                &pointer1,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
+        assert!(verifier.is_device_clear(DeviceId(1)));
+    }
+
+    /// Pack `action_index` into the high bits of a pointer action, matching the AMOTION_EVENT
+    /// encoding used for POINTER_DOWN/UP and HOVER_POINTER_ENTER/EXIT.
+    fn pointer_action(action: u32, action_index: usize) -> u32 {
+        action
+            | ((action_index as u32) << input_bindgen::AMOTION_EVENT_ACTION_POINTER_INDEX_SHIFT
+                & input_bindgen::AMOTION_EVENT_ACTION_POINTER_INDEX_MASK)
     }
 
     #[test]
     fn double_hover_enter() {
-        let mut verifier = InputVerifier::new("Test", /*should_log*/ false);
-        let pointer_properties = Vec::from([RustPointerProperties { id: 0 }]);
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties = Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
         assert!(verifier
             .process_movement(
                 DeviceId(1),
                 input_bindgen::AMOTION_EVENT_ACTION_HOVER_ENTER,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_ok());
 
@@ -440,7 +997,162 @@ mod tests {
                 input_bindgen::AMOTION_EVENT_ACTION_HOVER_ENTER,
                 &pointer_properties,
                 MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn touching_and_hovering_are_mutually_exclusive() {
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties =
+            Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_DOWN,
+                &pointer_properties,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_ok());
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_HOVER_ENTER,
+                &pointer_properties,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn strict_mode_rejects_teleporting_move() {
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ true);
+        verifier.set_move_delta_threshold(100.0);
+        let down = Vec::from([RustPointerProperties { id: 0, x: 10.0, y: 10.0, tool_type: 0 }]);
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_DOWN,
+                &down,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_ok());
+        let teleport = Vec::from([RustPointerProperties { id: 0, x: 10.0, y: 500.0, tool_type: 0 }]);
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_MOVE,
+                &teleport,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
             )
             .is_err());
     }
+
+    #[test]
+    fn strict_mode_rejects_non_finite_coordinates() {
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ true);
+        let down = Vec::from([RustPointerProperties { id: 0, x: f32::NAN, y: 0.0, tool_type: 0 }]);
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_DOWN,
+                &down,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_decreasing_event_time() {
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties =
+            Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_DOWN,
+                &pointer_properties,
+                MotionFlags::empty(),
+                /*event_time=*/ 10,
+                /*down_time=*/ 10,
+            )
+            .is_ok());
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_MOVE,
+                &pointer_properties,
+                MotionFlags::empty(),
+                /*event_time=*/ 5,
+                /*down_time=*/ 10,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_changing_down_time() {
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties =
+            Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_DOWN,
+                &pointer_properties,
+                MotionFlags::empty(),
+                /*event_time=*/ 10,
+                /*down_time=*/ 10,
+            )
+            .is_ok());
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_MOVE,
+                &pointer_properties,
+                MotionFlags::empty(),
+                /*event_time=*/ 20,
+                /*down_time=*/ 15,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn error_kind_is_payload_free_discriminant() {
+        let mut verifier = InputVerifier::new("Test", /*should_log*/ false, /*strict*/ false);
+        let pointer_properties =
+            Vec::from([RustPointerProperties { id: 0, x: 0.0, y: 0.0, tool_type: 0 }]);
+        assert!(verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_DOWN,
+                &pointer_properties,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .is_ok());
+        let err = verifier
+            .process_movement(
+                DeviceId(1),
+                input_bindgen::AMOTION_EVENT_ACTION_DOWN,
+                &pointer_properties,
+                MotionFlags::empty(),
+                /*event_time=*/ 0,
+                /*down_time=*/ 0,
+            )
+            .unwrap_err();
+        assert_eq!(err.kind(), VerificationErrorKind::AlreadyDown);
+    }
 }
@@ -0,0 +1,145 @@
+/*
+ * Copyright 2024 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Fuzzer for the InputVerifier. Decodes the raw byte stream into a sequence of synthetic motion
+//! operations and feeds them through a single long-lived `InputVerifier`, so that cross-event state
+//! is exercised. The verifier must never panic or index out of bounds on malformed input.
+
+#![allow(missing_docs)]
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+
+use input::input_verifier::InputVerifier;
+use input::{DeviceId, MotionFlags, RustPointerProperties};
+
+/// The maximum number of pointers we will synthesize for a single event. Real gestures never carry
+/// anywhere near this many, and clamping keeps the decoder from allocating unbounded vectors.
+const MAX_POINTERS: usize = 16;
+
+/// A cursor over the fuzzer input that hands out one byte at a time, yielding 0 once exhausted so
+/// the decoder always makes progress.
+struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn next(&mut self) -> u8 {
+        let byte = self.data.get(self.pos).copied().unwrap_or(0);
+        self.pos += 1;
+        byte
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let strict = data.first().is_some_and(|byte| byte & 1 == 1);
+    let mut verifier = InputVerifier::new("Fuzzer", /*should_log=*/ false, strict);
+    let mut reader = ByteReader::new(data);
+
+    while !reader.is_empty() {
+        let opcode = reader.next();
+        let device_id = DeviceId(reader.next() as i32);
+
+        // The reset opcode has no pointers; it simply clears the per-device state.
+        if opcode % 13 == 9 {
+            verifier.reset_device(device_id);
+            continue;
+        }
+
+        let pointer_count = (reader.next() as usize % MAX_POINTERS) + 1;
+        let pointer_properties: Vec<RustPointerProperties> = (0..pointer_count)
+            .map(|_| RustPointerProperties {
+                id: reader.next() as i32,
+                x: f32::from(reader.next()),
+                y: f32::from(reader.next()),
+                tool_type: reader.next() as i32,
+            })
+            .collect();
+        let flags = MotionFlags::from_bits_truncate(reader.next() as u32);
+
+        // The action_index, where relevant, is derived modulo the pointer count, so it always lands
+        // within the synthesized properties slice.
+        let action_index = reader.next() as usize % pointer_count;
+        let action = match opcode % 13 {
+            0 => input_bindgen::AMOTION_EVENT_ACTION_DOWN,
+            1 => action_with_index(input_bindgen::AMOTION_EVENT_ACTION_POINTER_DOWN, action_index),
+            2 => input_bindgen::AMOTION_EVENT_ACTION_MOVE,
+            3 => action_with_index(input_bindgen::AMOTION_EVENT_ACTION_POINTER_UP, action_index),
+            4 => input_bindgen::AMOTION_EVENT_ACTION_UP,
+            5 => input_bindgen::AMOTION_EVENT_ACTION_CANCEL,
+            6 => input_bindgen::AMOTION_EVENT_ACTION_HOVER_ENTER,
+            7 => input_bindgen::AMOTION_EVENT_ACTION_HOVER_MOVE,
+            8 => input_bindgen::AMOTION_EVENT_ACTION_HOVER_EXIT,
+            10 => action_with_index(
+                input_bindgen::AMOTION_EVENT_ACTION_HOVER_POINTER_ENTER,
+                action_index,
+            ),
+            _ => action_with_index(
+                input_bindgen::AMOTION_EVENT_ACTION_HOVER_POINTER_EXIT,
+                action_index,
+            ),
+        };
+
+        // Derive timestamps from the stream so the monotonicity checks are exercised too.
+        let event_time = i64::from(reader.next());
+        let down_time = i64::from(reader.next());
+
+        // Both accepted and rejected events are valid fuzzer outcomes; the baseline assertion is
+        // simply that process_movement always returns instead of panicking or indexing past the
+        // end of the properties slice.
+        let result = verifier.process_movement(
+            device_id,
+            action,
+            &pointer_properties,
+            flags,
+            event_time,
+            down_time,
+        );
+
+        // After any accepted terminal transition the device's per-pointer bookkeeping must be
+        // empty; a regression that leaves a stale set behind is surfaced here rather than only on
+        // a hard panic.
+        if result.is_ok()
+            && matches!(
+                action,
+                input_bindgen::AMOTION_EVENT_ACTION_UP
+                    | input_bindgen::AMOTION_EVENT_ACTION_CANCEL
+                    | input_bindgen::AMOTION_EVENT_ACTION_HOVER_EXIT
+            )
+        {
+            assert!(
+                verifier.is_device_clear(device_id),
+                "device {device_id:?} still has tracked pointers after an accepted terminal event"
+            );
+        }
+    }
+});
+
+/// Pack `action_index` into the high bits of a pointer action, matching the AMOTION_EVENT encoding.
+fn action_with_index(action: u32, action_index: usize) -> u32 {
+    action
+        | ((action_index as u32) << input_bindgen::AMOTION_EVENT_ACTION_POINTER_INDEX_SHIFT
+            & input_bindgen::AMOTION_EVENT_ACTION_POINTER_INDEX_MASK)
+}
@@ -16,8 +16,9 @@
 
 extern crate nativewindow_bindgen as ffi;
 
-pub use ffi::{AHardwareBuffer_Format, AHardwareBuffer_UsageFlags};
+pub use ffi::{AHardwareBuffer_Format, AHardwareBuffer_UsageFlags, ARect};
 
+use std::os::fd::{AsRawFd, BorrowedFd, RawFd};
 use std::os::raw::c_void;
 use std::ptr;
 
@@ -106,6 +107,55 @@ impl AHardwareBuffer {
         Self(buffer_ptr as *mut ffi::AHardwareBuffer)
     }
 
+    /// Acquires a new reference to the buffer behind the raw pointer and wraps it in a Rust
+    /// AHardwareBuffer. Unlike [`take_from_raw`](Self::take_from_raw), this increments the refcount,
+    /// so the caller keeps ownership of its own reference and nothing leaks: both handles release
+    /// exactly the reference they hold.
+    ///
+    /// # Errors
+    ///
+    /// Will panic if buffer_ptr is null.
+    ///
+    /// # Safety
+    ///
+    /// `buffer_ptr` must point to a valid, live AHardwareBuffer.
+    pub unsafe fn clone_from_raw(buffer_ptr: *mut c_void) -> Self {
+        assert!(!buffer_ptr.is_null());
+        let buffer = buffer_ptr as *mut ffi::AHardwareBuffer;
+        // SAFETY: The caller guarantees the pointer is valid and live.
+        unsafe { ffi::AHardwareBuffer_acquire(buffer) };
+        Self(buffer)
+    }
+
+    /// Borrow the underlying raw pointer, e.g. to import the buffer into Vulkan as external memory
+    /// via `VkImportAndroidHardwareBufferInfoANDROID`. The buffer retains ownership; the pointer is
+    /// only valid while `self` is alive.
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.0 as *mut c_void
+    }
+
+    /// Consume the wrapper and return the raw pointer WITHOUT releasing the reference, transferring
+    /// ownership to the caller (typically C or Vulkan). The caller becomes responsible for the
+    /// reference, e.g. by eventually calling `AHardwareBuffer_release` or re-adopting it with
+    /// [`take_from_raw`](Self::take_from_raw).
+    pub fn into_raw(self) -> *mut c_void {
+        let buffer_ptr = self.0;
+        std::mem::forget(self);
+        buffer_ptr as *mut c_void
+    }
+
+    /// Whether the buffer can be sampled by the GPU, i.e. its usage includes
+    /// `AHARDWAREBUFFER_USAGE_GPU_SAMPLED_IMAGE`.
+    pub fn is_gpu_sampleable(&self) -> bool {
+        self.usage().0 & AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_GPU_SAMPLED_IMAGE.0 != 0
+    }
+
+    /// Whether the buffer can be used as a GPU render target, i.e. its usage includes
+    /// `AHARDWAREBUFFER_USAGE_GPU_FRAMEBUFFER`.
+    pub fn is_gpu_framebuffer(&self) -> bool {
+        self.usage().0 & AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_GPU_FRAMEBUFFER.0 != 0
+    }
+
     /// Get the system wide unique id for an AHardwareBuffer. This function may panic in extreme
     /// and undocumented circumstances.
     ///
@@ -149,6 +199,140 @@ impl AHardwareBuffer {
         self.description().stride
     }
 
+    /// Lock the buffer for direct CPU access, returning an RAII guard that exposes the mapped
+    /// virtual address alongside the stride, height, and bytes-per-pixel needed to interpret it as
+    /// a pixel buffer. The buffer is unlocked when the guard is dropped. If `rect` is given, only
+    /// the rows it covers are guaranteed to be synchronized, so the guard only exposes those rows
+    /// (clamped to the buffer's actual height); pass `None` to lock and expose the whole buffer.
+    ///
+    /// `usage` must contain a CPU read and/or write flag, and the buffer must be single-plane;
+    /// planar formats must go through [`AHardwareBuffer::lock_planes`]. Returns `None` if those
+    /// preconditions are not met or if the underlying lock fails.
+    ///
+    /// Available since API level 26.
+    pub fn lock(
+        &self,
+        usage: AHardwareBuffer_UsageFlags,
+        fence_fd: Option<RawFd>,
+        rect: Option<ARect>,
+    ) -> Option<HardwareBufferGuard<'_>> {
+        if !is_cpu_usage(usage) {
+            return None;
+        }
+        let bytes_per_pixel = bytes_per_pixel(self.format())?;
+
+        let fence = fence_fd.unwrap_or(-1);
+        let rect_ptr = rect.as_ref().map_or(ptr::null(), |rect| rect as *const ARect);
+        let mut virtual_address = ptr::null_mut();
+        // SAFETY: The buffer pointer is non-null, and the out-parameter is a valid local. The
+        // returned address is valid until the matching unlock, which the guard performs on drop.
+        let status = unsafe {
+            ffi::AHardwareBuffer_lock(self.0, usage.0, fence, rect_ptr, &mut virtual_address)
+        };
+        if status != 0 || virtual_address.is_null() {
+            return None;
+        }
+
+        // Only the rows within `rect` (or the whole buffer, if no rect was given) are guaranteed
+        // to be synchronized by the lock, so the guard must only expose those rows.
+        let (row_offset, height) = locked_rows(rect, self.height());
+        let row_bytes = self.stride() as usize * bytes_per_pixel as usize;
+        // SAFETY: `row_offset` is clamped to at most `self.height()`, so the offset address is
+        // still within the buffer that was just locked.
+        let virtual_address = unsafe { (virtual_address as *mut u8).add(row_offset * row_bytes) };
+
+        Some(HardwareBufferGuard {
+            buffer: self,
+            virtual_address: virtual_address as *mut c_void,
+            stride: self.stride(),
+            height,
+            bytes_per_pixel,
+        })
+    }
+
+    /// Send the buffer's handle over a Unix domain socket, so that another process can reconstruct
+    /// it with [`recv_handle`](Self::recv_handle). The receiving process obtains its own reference;
+    /// this process keeps ownership of the buffer.
+    ///
+    /// Returns the non-zero status code from the NDK on failure.
+    ///
+    /// Available since API level 28.
+    pub fn send_handle(&self, socket_fd: BorrowedFd) -> Result<(), i32> {
+        // SAFETY: The buffer pointer is non-null and the fd is valid for the duration of the call.
+        let status = unsafe {
+            ffi::AHardwareBuffer_sendHandleToUnixSocket(self.0, socket_fd.as_raw_fd())
+        };
+        if status == 0 {
+            Ok(())
+        } else {
+            Err(status)
+        }
+    }
+
+    /// Receive a buffer handle previously sent with [`send_handle`](Self::send_handle) over a Unix
+    /// domain socket. The returned buffer already owns a reference, so no extra acquire is needed.
+    /// Returns `None` if the receive fails or yields a null buffer.
+    ///
+    /// Available since API level 28.
+    pub fn recv_handle(socket_fd: BorrowedFd) -> Option<Self> {
+        let mut buffer = ptr::null_mut();
+        // SAFETY: The fd is valid for the duration of the call and the out-parameter is a valid
+        // local. On success the NDK hands us a buffer that already owns a reference.
+        let status = unsafe {
+            ffi::AHardwareBuffer_recvHandleFromUnixSocket(socket_fd.as_raw_fd(), &mut buffer)
+        };
+        if status == 0 && !buffer.is_null() {
+            Some(Self(buffer))
+        } else {
+            None
+        }
+    }
+
+    /// Lock the buffer for CPU access and describe the layout of every plane, for multi-planar
+    /// formats such as `YV12`, `Y8`, or `YCbCr_420_SP`. Returns an RAII guard that unlocks the
+    /// buffer on drop and exposes a [`PlaneDescriptor`] per plane with its mapped base pointer,
+    /// pixel stride, and row stride.
+    ///
+    /// `usage` must contain a CPU read and/or write flag. Returns `None` if that precondition is
+    /// not met or if the underlying lock fails.
+    ///
+    /// Available since API level 29.
+    pub fn lock_planes(
+        &self,
+        usage: AHardwareBuffer_UsageFlags,
+        fence_fd: Option<RawFd>,
+        rect: Option<ARect>,
+    ) -> Option<HardwareBufferPlanesGuard<'_>> {
+        if !is_cpu_usage(usage) {
+            return None;
+        }
+
+        let fence = fence_fd.unwrap_or(-1);
+        let rect_ptr = rect.as_ref().map_or(ptr::null(), |rect| rect as *const ARect);
+        // SAFETY: A zeroed AHardwareBuffer_Planes is a valid (empty) out-parameter; lockPlanes
+        // fills it in. All of its fields are plain integers and pointers.
+        let mut planes: ffi::AHardwareBuffer_Planes = unsafe { std::mem::zeroed() };
+        // SAFETY: The buffer pointer is non-null and the out-parameter is a valid local. The mapped
+        // addresses are valid until the matching unlock, which the guard performs on drop.
+        let status = unsafe {
+            ffi::AHardwareBuffer_lockPlanes(self.0, usage.0, fence, rect_ptr, &mut planes)
+        };
+        if status != 0 {
+            return None;
+        }
+
+        let count = (planes.planeCount as usize).min(planes.planes.len());
+        let descriptors = planes.planes[..count]
+            .iter()
+            .map(|plane| PlaneDescriptor {
+                data: plane.data,
+                pixel_stride: plane.pixelStride,
+                row_stride: plane.rowStride,
+            })
+            .collect();
+        Some(HardwareBufferPlanesGuard { buffer: self, planes: descriptors })
+    }
+
     fn description(&self) -> ffi::AHardwareBuffer_Desc {
         let mut buffer_desc = ffi::AHardwareBuffer_Desc {
             width: 0,
@@ -166,6 +350,171 @@ impl AHardwareBuffer {
     }
 }
 
+/// RAII guard returned by [`AHardwareBuffer::lock`]. Holds the buffer locked for CPU access and
+/// unlocks it on drop. The mapped region is `stride * height * bytes_per_pixel` bytes, starting at
+/// the first row covered by the `rect` passed to `lock` (or row 0, if no `rect` was given); rows
+/// outside that range are not exposed, since they aren't guaranteed to be synchronized.
+pub struct HardwareBufferGuard<'a> {
+    buffer: &'a AHardwareBuffer,
+    virtual_address: *mut c_void,
+    stride: u32,
+    height: u32,
+    bytes_per_pixel: u32,
+}
+
+impl HardwareBufferGuard<'_> {
+    /// The row stride of the mapped buffer, in pixels.
+    pub fn stride(&self) -> u32 {
+        self.stride
+    }
+
+    /// The number of rows in the mapped (locked) region.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The number of bytes occupied by a single pixel in the mapped buffer.
+    pub fn bytes_per_pixel(&self) -> u32 {
+        self.bytes_per_pixel
+    }
+
+    /// The size of the mapped region, in bytes.
+    fn size(&self) -> usize {
+        self.stride as usize * self.height as usize * self.bytes_per_pixel as usize
+    }
+
+    /// View the mapped region as a shared byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The address was returned by a successful lock and remains valid for the lifetime
+        // of the guard, and `size` is computed from the buffer's own stride/height/bpp.
+        unsafe { std::slice::from_raw_parts(self.virtual_address as *const u8, self.size()) }
+    }
+
+    /// View the mapped region as a mutable byte slice.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: As above; `&mut self` guarantees no other reference into the region exists.
+        unsafe { std::slice::from_raw_parts_mut(self.virtual_address as *mut u8, self.size()) }
+    }
+
+    /// Unlock the buffer explicitly, returning the release fence fd if one was produced (`None`
+    /// when there is no fence). Consuming the guard this way avoids the fence being dropped
+    /// silently as it would be on a plain `Drop`.
+    pub fn unlock(self) -> Option<RawFd> {
+        let mut fence: i32 = -1;
+        // SAFETY: The buffer pointer is non-null and was locked by the matching `lock` call.
+        unsafe { ffi::AHardwareBuffer_unlock(self.buffer.0, &mut fence) };
+        // The buffer has been unlocked; forget the guard so its Drop doesn't unlock a second time.
+        std::mem::forget(self);
+        if fence >= 0 {
+            Some(fence)
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for HardwareBufferGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: The buffer pointer is non-null and was locked when the guard was created. The
+        // release fence, if any, is ignored on drop; use `unlock` to retrieve it.
+        unsafe { ffi::AHardwareBuffer_unlock(self.buffer.0, ptr::null_mut()) };
+    }
+}
+
+/// Describes a single plane of a locked multi-planar buffer: its mapped base pointer, the byte
+/// offset between consecutive pixels (`pixel_stride`), and the byte offset between consecutive rows
+/// (`row_stride`).
+pub struct PlaneDescriptor {
+    data: *mut c_void,
+    pixel_stride: u32,
+    row_stride: u32,
+}
+
+impl PlaneDescriptor {
+    /// The mapped base address of this plane.
+    pub fn data(&self) -> *mut c_void {
+        self.data
+    }
+
+    /// The byte offset between consecutive pixels within a row.
+    pub fn pixel_stride(&self) -> u32 {
+        self.pixel_stride
+    }
+
+    /// The byte offset between consecutive rows.
+    pub fn row_stride(&self) -> u32 {
+        self.row_stride
+    }
+}
+
+/// RAII guard returned by [`AHardwareBuffer::lock_planes`]. Holds the buffer locked for CPU access
+/// and unlocks it on drop, exposing one [`PlaneDescriptor`] per plane.
+pub struct HardwareBufferPlanesGuard<'a> {
+    buffer: &'a AHardwareBuffer,
+    planes: Vec<PlaneDescriptor>,
+}
+
+impl HardwareBufferPlanesGuard<'_> {
+    /// The planes of the locked buffer, in plane order.
+    pub fn planes(&self) -> &[PlaneDescriptor] {
+        &self.planes
+    }
+}
+
+impl Drop for HardwareBufferPlanesGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: The buffer pointer is non-null and was locked when the guard was created.
+        unsafe { ffi::AHardwareBuffer_unlock(self.buffer.0, ptr::null_mut()) };
+    }
+}
+
+/// Whether a usage bitvector requests CPU read or write access.
+fn is_cpu_usage(usage: AHardwareBuffer_UsageFlags) -> bool {
+    const CPU_READ: u64 =
+        AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_MASK.0;
+    const CPU_WRITE: u64 =
+        AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_WRITE_MASK.0;
+    usage.0 & (CPU_READ | CPU_WRITE) != 0
+}
+
+/// The row range, as `(first_row, row_count)`, that a lock of `rect` (or the whole buffer, if
+/// `rect` is `None`) actually synchronizes, clamped to `buffer_height`. Rows outside this range
+/// are not guaranteed to be coherent and must not be exposed through the lock guard.
+fn locked_rows(rect: Option<ARect>, buffer_height: u32) -> (usize, u32) {
+    let Some(rect) = rect else {
+        return (0, buffer_height);
+    };
+    let top = rect.top.max(0) as u32;
+    let bottom = rect.bottom.max(rect.top).max(0) as u32;
+    let top = top.min(buffer_height);
+    let bottom = bottom.min(buffer_height);
+    (top as usize, bottom - top)
+}
+
+/// The number of bytes per pixel for the single-plane formats supported by the simple CPU mapping
+/// path. Returns `None` for planar or otherwise unsupported formats.
+fn bytes_per_pixel(format: AHardwareBuffer_Format::Type) -> Option<u32> {
+    match format {
+        AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8A8_UNORM
+        | AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8X8_UNORM
+        | AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R10G10B10A2_UNORM => Some(4),
+        AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R16G16B16A16_FLOAT => Some(8),
+        AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8_UNORM => Some(3),
+        AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R5G6B5_UNORM => Some(2),
+        AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8_UNORM => Some(1),
+        _ => None,
+    }
+}
+
+impl Clone for AHardwareBuffer {
+    fn clone(&self) -> Self {
+        // SAFETY: self.0 will never be null. Acquiring increments the refcount so that the extra
+        // release performed when this clone is dropped keeps the refcount balanced.
+        unsafe { ffi::AHardwareBuffer_acquire(self.0) };
+        Self(self.0)
+    }
+}
+
 impl Drop for AHardwareBuffer {
     fn drop(&mut self) {
         // SAFETY: self.0 will never be null. AHardwareBuffers allocated from within Rust will have
@@ -175,6 +524,14 @@ impl Drop for AHardwareBuffer {
     }
 }
 
+// SAFETY: An AHardwareBuffer is a reference-counted gralloc handle. The NDK acquire/release/lock
+// functions are internally synchronized, and the handle is designed to be shared across threads
+// and even processes, so it is safe to send the wrapper between threads and to share it by
+// reference.
+unsafe impl Send for AHardwareBuffer {}
+// SAFETY: See the Send justification above.
+unsafe impl Sync for AHardwareBuffer {}
+
 #[cfg(test)]
 mod ahardwarebuffer_tests {
     use super::*;
@@ -250,6 +607,160 @@ mod ahardwarebuffer_tests {
         );
     }
 
+    #[test]
+    fn gpu_usage_flags_and_raw_pointer_round_trip() {
+        let buffer = AHardwareBuffer::new(
+            4,
+            4,
+            1,
+            AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8A8_UNORM,
+            AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_GPU_SAMPLED_IMAGE,
+        )
+        .expect("Buffer with some basic parameters was not created successfully");
+        assert!(buffer.is_gpu_sampleable());
+        assert!(!buffer.is_gpu_framebuffer());
+
+        let raw = buffer.into_raw();
+        // SAFETY: `raw` was just produced by `into_raw` on a valid buffer that hasn't been freed.
+        let reclaimed = unsafe { AHardwareBuffer::take_from_raw(raw) };
+        assert_eq!(reclaimed.width(), 4);
+    }
+
+    #[test]
+    fn lock_planes_returns_plane_descriptors() {
+        let buffer = AHardwareBuffer::new(
+            4,
+            4,
+            1,
+            AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8A8_UNORM,
+            AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_OFTEN,
+        )
+        .expect("Buffer with some basic parameters was not created successfully");
+
+        let guard = buffer
+            .lock_planes(AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_OFTEN, None, None)
+            .expect("failed to lock planes");
+        assert!(!guard.planes().is_empty());
+        assert!(guard.planes()[0].pixel_stride() > 0);
+    }
+
+    #[test]
+    fn lock_planes_with_rect_still_returns_plane_descriptors() {
+        let buffer = AHardwareBuffer::new(
+            4,
+            8,
+            1,
+            AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8A8_UNORM,
+            AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_OFTEN,
+        )
+        .expect("Buffer with some basic parameters was not created successfully");
+
+        let rect = ARect { left: 0, top: 2, right: 4, bottom: 6 };
+        let guard = buffer
+            .lock_planes(
+                AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_OFTEN,
+                None,
+                Some(rect),
+            )
+            .expect("failed to lock plane rect");
+        assert!(!guard.planes().is_empty());
+        assert!(guard.planes()[0].pixel_stride() > 0);
+    }
+
+    #[test]
+    fn send_and_recv_handle_round_trip() {
+        use std::os::fd::AsFd;
+
+        let buffer = AHardwareBuffer::new(
+            4,
+            4,
+            1,
+            AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8A8_UNORM,
+            AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_OFTEN,
+        )
+        .expect("Buffer with some basic parameters was not created successfully");
+        let id = buffer.id();
+
+        let (sender, receiver) =
+            std::os::unix::net::UnixDatagram::pair().expect("failed to create socket pair");
+        buffer.send_handle(sender.as_fd()).expect("failed to send buffer handle");
+        let received =
+            AHardwareBuffer::recv_handle(receiver.as_fd()).expect("failed to receive buffer handle");
+        assert_eq!(received.id(), id);
+    }
+
+    #[test]
+    fn clone_keeps_buffer_alive_after_original_drops() {
+        let buffer = AHardwareBuffer::new(
+            4,
+            4,
+            1,
+            AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8A8_UNORM,
+            AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_OFTEN,
+        )
+        .expect("Buffer with some basic parameters was not created successfully");
+        let id = buffer.id();
+
+        let clone = buffer.clone();
+        drop(buffer);
+        // If Clone/Drop left the refcount unbalanced, dropping the original would have freed the
+        // buffer out from under the clone, and these getters would use-after-free or assert.
+        assert_eq!(clone.id(), id);
+        assert_eq!(clone.width(), 4);
+    }
+
+    #[test]
+    fn lock_allows_cpu_access_to_bytes() {
+        let buffer = AHardwareBuffer::new(
+            4,
+            4,
+            1,
+            AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8A8_UNORM,
+            AHardwareBuffer_UsageFlags(
+                AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_OFTEN.0
+                    | AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_WRITE_OFTEN.0,
+            ),
+        )
+        .expect("Buffer with some basic parameters was not created successfully");
+
+        let mut guard = buffer
+            .lock(AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_WRITE_OFTEN, None, None)
+            .expect("failed to lock buffer for CPU access");
+        assert_eq!(guard.bytes_per_pixel(), 4);
+        guard.as_bytes_mut().fill(0xab);
+        assert!(guard.as_bytes().iter().all(|&b| b == 0xab));
+    }
+
+    #[test]
+    fn lock_with_rect_only_exposes_the_locked_rows() {
+        let buffer = AHardwareBuffer::new(
+            4,
+            8,
+            1,
+            AHardwareBuffer_Format::AHARDWAREBUFFER_FORMAT_R8G8B8A8_UNORM,
+            AHardwareBuffer_UsageFlags(
+                AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_READ_OFTEN.0
+                    | AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_WRITE_OFTEN.0,
+            ),
+        )
+        .expect("Buffer with some basic parameters was not created successfully");
+
+        // Lock only rows [2, 6) out of the buffer's 8 rows.
+        let rect = ARect { left: 0, top: 2, right: 4, bottom: 6 };
+        let mut guard = buffer
+            .lock(
+                AHardwareBuffer_UsageFlags::AHARDWAREBUFFER_USAGE_CPU_WRITE_OFTEN,
+                None,
+                Some(rect),
+            )
+            .expect("failed to lock buffer rect for CPU access");
+        assert_eq!(guard.height(), 4);
+        let expected_len = guard.stride() as usize * 4 * guard.bytes_per_pixel() as usize;
+        assert_eq!(guard.as_bytes().len(), expected_len);
+        guard.as_bytes_mut().fill(0xcd);
+        assert!(guard.as_bytes().iter().all(|&b| b == 0xcd));
+    }
+
     #[test]
     fn id_getter() {
         let buffer = AHardwareBuffer::new(
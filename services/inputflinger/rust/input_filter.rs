@@ -24,9 +24,11 @@ use com_android_server_inputflinger::aidl::com::android::server::inputflinger::{
     IInputFilter::{IInputFilter, IInputFilterCallbacks::IInputFilterCallbacks},
     InputFilterConfiguration::InputFilterConfiguration,
     KeyEvent::KeyEvent,
+    MotionEvent::MotionEvent,
 };
 
 use crate::bounce_keys_filter::BounceKeysFilter;
+use crate::slow_keys_filter::SlowKeysFilter;
 use crate::sticky_keys_filter::StickyKeysFilter;
 use log::{error, info};
 use std::sync::{Arc, Mutex, RwLock};
@@ -34,7 +36,16 @@ use std::sync::{Arc, Mutex, RwLock};
 /// Interface for all the sub input filters
 pub trait Filter {
     fn notify_key(&mut self, event: &KeyEvent);
+    /// Defaults to a no-op so filters that only care about key events (e.g. Sticky keys, Bounce
+    /// keys) don't have to implement it. Filters that do care (e.g. Slow keys) override this to
+    /// forward the event to `next`.
+    fn notify_motion(&mut self, _event: &MotionEvent) {}
     fn notify_devices_changed(&mut self, device_infos: &[DeviceInfo]);
+    /// Called when a timeout previously requested via
+    /// `IInputFilterCallbacks::requestTimeoutAtTime` elapses. Filters that defer events (e.g. Slow
+    /// keys) use this to emit buffered events asynchronously. Defaults to a no-op so filters that
+    /// never request a timeout (e.g. Sticky keys, Bounce keys) don't have to implement it.
+    fn notify_key_timeout_expired(&mut self, _when_nanos: i64) {}
 }
 
 struct InputFilterState {
@@ -82,12 +93,24 @@ impl IInputFilter for InputFilter {
         Result::Ok(())
     }
 
+    fn notifyMotion(&self, event: &MotionEvent) -> binder::Result<()> {
+        let first_filter = &mut self.state.lock().unwrap().first_filter;
+        first_filter.notify_motion(event);
+        Result::Ok(())
+    }
+
     fn notifyInputDevicesChanged(&self, device_infos: &[DeviceInfo]) -> binder::Result<()> {
         let first_filter = &mut self.state.lock().unwrap().first_filter;
         first_filter.notify_devices_changed(device_infos);
         Result::Ok(())
     }
 
+    fn notifyKeyTimeoutExpired(&self, when_nanos: i64) -> binder::Result<()> {
+        let first_filter = &mut self.state.lock().unwrap().first_filter;
+        first_filter.notify_key_timeout_expired(when_nanos);
+        Result::Ok(())
+    }
+
     fn notifyConfigurationChanged(&self, config: &InputFilterConfiguration) -> binder::Result<()> {
         let mut state = self.state.lock().unwrap();
         let mut first_filter: Box<dyn Filter + Send + Sync> =
@@ -106,6 +129,15 @@ impl IInputFilter for InputFilter {
             state.enabled = true;
             info!("Bounce keys filter is installed");
         }
+        if config.slowKeysThresholdNs > 0 {
+            first_filter = Box::new(SlowKeysFilter::new(
+                first_filter,
+                config.slowKeysThresholdNs,
+                self.callbacks.clone(),
+            ));
+            state.enabled = true;
+            info!("Slow keys filter is installed");
+        }
         state.first_filter = first_filter;
         Result::Ok(())
     }
@@ -129,9 +161,20 @@ impl Filter for BaseFilter {
         }
     }
 
+    fn notify_motion(&mut self, event: &MotionEvent) {
+        match self.callbacks.read().unwrap().sendMotionEvent(event) {
+            Ok(_) => (),
+            _ => error!("Failed to send motion event back to native C++"),
+        }
+    }
+
     fn notify_devices_changed(&mut self, _device_infos: &[DeviceInfo]) {
         // do nothing
     }
+
+    fn notify_key_timeout_expired(&mut self, _when_nanos: i64) {
+        // The base filter has no deferred events of its own, so there is nothing to emit.
+    }
 }
 
 pub struct ModifierStateListener {
@@ -219,6 +262,7 @@ mod tests {
         let result = input_filter.notifyConfigurationChanged(&InputFilterConfiguration {
             bounceKeysThresholdNs: 100,
             stickyKeysEnabled: false,
+            slowKeysThresholdNs: 0,
         });
         assert!(result.is_ok());
         let result = input_filter.isEnabled();
@@ -233,6 +277,22 @@ mod tests {
         let result = input_filter.notifyConfigurationChanged(&InputFilterConfiguration {
             bounceKeysThresholdNs: 0,
             stickyKeysEnabled: true,
+            slowKeysThresholdNs: 0,
+        });
+        assert!(result.is_ok());
+        let result = input_filter.isEnabled();
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn test_notify_configuration_changed_enabled_slow_keys() {
+        let test_callbacks = TestCallbacks::new();
+        let input_filter = InputFilter::new(Strong::new(Box::new(test_callbacks)));
+        let result = input_filter.notifyConfigurationChanged(&InputFilterConfiguration {
+            bounceKeysThresholdNs: 0,
+            stickyKeysEnabled: false,
+            slowKeysThresholdNs: 100,
         });
         assert!(result.is_ok());
         let result = input_filter.isEnabled();
@@ -263,7 +323,7 @@ mod tests {
 pub mod test_filter {
     use crate::input_filter::Filter;
     use com_android_server_inputflinger::aidl::com::android::server::inputflinger::{
-        DeviceInfo::DeviceInfo, KeyEvent::KeyEvent,
+        DeviceInfo::DeviceInfo, KeyEvent::KeyEvent, MotionEvent::MotionEvent,
     };
     use std::sync::{Arc, RwLock, RwLockWriteGuard};
 
@@ -271,6 +331,7 @@ pub mod test_filter {
     struct TestFilterInner {
         is_device_changed_called: bool,
         last_event: Option<KeyEvent>,
+        last_motion_event: Option<MotionEvent>,
     }
 
     #[derive(Default, Clone)]
@@ -289,8 +350,13 @@ pub mod test_filter {
             self.0.read().unwrap().last_event
         }
 
+        pub fn last_motion_event(&self) -> Option<MotionEvent> {
+            self.0.read().unwrap().last_motion_event.clone()
+        }
+
         pub fn clear(&mut self) {
-            self.inner().last_event = None
+            self.inner().last_event = None;
+            self.inner().last_motion_event = None;
         }
 
         pub fn is_device_changed_called(&self) -> bool {
@@ -302,9 +368,15 @@ pub mod test_filter {
         fn notify_key(&mut self, event: &KeyEvent) {
             self.inner().last_event = Some(*event);
         }
+        fn notify_motion(&mut self, event: &MotionEvent) {
+            self.inner().last_motion_event = Some(event.clone());
+        }
         fn notify_devices_changed(&mut self, _device_infos: &[DeviceInfo]) {
             self.inner().is_device_changed_called = true;
         }
+        fn notify_key_timeout_expired(&mut self, _when_nanos: i64) {
+            // The test filter is terminal; it records events it is told to emit, not timeouts.
+        }
     }
 }
 
@@ -313,6 +385,7 @@ pub mod test_callbacks {
     use binder::Interface;
     use com_android_server_inputflinger::aidl::com::android::server::inputflinger::{
         IInputFilter::IInputFilterCallbacks::IInputFilterCallbacks, KeyEvent::KeyEvent,
+        MotionEvent::MotionEvent,
     };
     use std::sync::{Arc, RwLock, RwLockWriteGuard};
 
@@ -321,6 +394,8 @@ pub mod test_callbacks {
         last_modifier_state: u32,
         last_locked_modifier_state: u32,
         last_event: Option<KeyEvent>,
+        last_motion_event: Option<MotionEvent>,
+        last_timeout: Option<i64>,
     }
 
     #[derive(Default, Clone)]
@@ -341,8 +416,13 @@ pub mod test_callbacks {
             self.0.read().unwrap().last_event
         }
 
+        pub fn last_motion_event(&self) -> Option<MotionEvent> {
+            self.0.read().unwrap().last_motion_event.clone()
+        }
+
         pub fn clear(&mut self) {
             self.inner().last_event = None;
+            self.inner().last_motion_event = None;
             self.inner().last_modifier_state = 0;
             self.inner().last_locked_modifier_state = 0;
         }
@@ -354,6 +434,10 @@ pub mod test_callbacks {
         pub fn get_last_locked_modifier_state(&self) -> u32 {
             self.0.read().unwrap().last_locked_modifier_state
         }
+
+        pub fn last_timeout(&self) -> Option<i64> {
+            self.0.read().unwrap().last_timeout
+        }
     }
 
     impl IInputFilterCallbacks for TestCallbacks {
@@ -362,6 +446,11 @@ pub mod test_callbacks {
             Result::Ok(())
         }
 
+        fn sendMotionEvent(&self, event: &MotionEvent) -> binder::Result<()> {
+            self.inner().last_motion_event = Some(event.clone());
+            Result::Ok(())
+        }
+
         fn onModifierStateChanged(
             &self,
             modifier_state: i32,
@@ -371,5 +460,10 @@ pub mod test_callbacks {
             self.inner().last_locked_modifier_state = locked_modifier_state as u32;
             Result::Ok(())
         }
+
+        fn requestTimeoutAtTime(&self, when_nanos: i64) -> binder::Result<()> {
+            self.inner().last_timeout = Some(when_nanos);
+            Result::Ok(())
+        }
     }
 }
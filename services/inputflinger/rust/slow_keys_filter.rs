@@ -0,0 +1,231 @@
+/*
+ * Copyright 2024 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Slow keys input filter implementation.
+//! Slow keys is an accessibility feature that holds back a key press and only forwards it if the
+//! key is kept physically pressed for at least a configured threshold duration. Presses that are
+//! released sooner are discarded, which helps users who tend to bump keys accidentally.
+
+use crate::input_filter::Filter;
+use binder::Strong;
+use com_android_server_inputflinger::aidl::com::android::server::inputflinger::{
+    DeviceInfo::DeviceInfo, IInputFilter::IInputFilterCallbacks::IInputFilterCallbacks,
+    KeyEvent::KeyEvent, KeyEventAction::KeyEventAction, MotionEvent::MotionEvent,
+};
+use log::error;
+use std::sync::{Arc, RwLock};
+
+/// A key that is currently being held back, identified by the device and key code that produced it.
+#[derive(PartialEq, Eq)]
+struct PendingKey {
+    device_id: i32,
+    keycode: i32,
+}
+
+/// A DOWN that is being held back until its deadline (the DOWN's `eventTime` plus the threshold)
+/// passes, at which point it is forwarded while the key is still physically pressed.
+struct OngoingKeyDown {
+    key: PendingKey,
+    emit_time: i64,
+    event: KeyEvent,
+}
+
+pub struct SlowKeysFilter {
+    next: Box<dyn Filter + Send + Sync>,
+    threshold_ns: i64,
+    callbacks: Arc<RwLock<Strong<dyn IInputFilterCallbacks>>>,
+    // The DOWN events that are being held back, in the order they must be forwarded once their
+    // deadline elapses. A DOWN is removed from here either when its deadline fires (forwarded) or
+    // when a matching UP arrives before the deadline (dropped together with the UP).
+    pending_downs: Vec<OngoingKeyDown>,
+}
+
+impl SlowKeysFilter {
+    /// Create a new SlowKeysFilter that forwards accepted events to `next`, holding back key
+    /// presses shorter than `threshold_ns` nanoseconds. Deferred deliveries are scheduled through
+    /// `callbacks` and arrive back via `notify_key_timeout_expired`.
+    pub fn new(
+        next: Box<dyn Filter + Send + Sync>,
+        threshold_ns: i64,
+        callbacks: Arc<RwLock<Strong<dyn IInputFilterCallbacks>>>,
+    ) -> SlowKeysFilter {
+        Self { next, threshold_ns, callbacks, pending_downs: Vec::new() }
+    }
+
+    fn request_timeout_at(&self, when_nanos: i64) {
+        if let Err(e) = self.callbacks.read().unwrap().requestTimeoutAtTime(when_nanos) {
+            error!("Failed to request slow keys timeout: {e:?}");
+        }
+    }
+}
+
+impl Filter for SlowKeysFilter {
+    fn notify_key(&mut self, event: &KeyEvent) {
+        let key = PendingKey { device_id: event.deviceId, keycode: event.keyCode };
+        match event.action {
+            KeyEventAction::DOWN => {
+                // Hold the DOWN back and ask to be woken once the threshold elapses, so the key
+                // registers while it is still pressed instead of only on release. A DOWN for a
+                // key that's already pending (e.g. a replayed or corrupted stream without an
+                // intervening UP) replaces the old entry instead of queuing a second one; the
+                // earlier timeout then finds nothing due and forwards nothing.
+                let emit_time = event.eventTime + self.threshold_ns;
+                if let Some(pos) = self.pending_downs.iter().position(|down| down.key == key) {
+                    self.pending_downs[pos] = OngoingKeyDown { key, emit_time, event: *event };
+                } else {
+                    self.pending_downs.push(OngoingKeyDown { key, emit_time, event: *event });
+                }
+                self.request_timeout_at(emit_time);
+            }
+            KeyEventAction::UP => {
+                if let Some(pos) = self.pending_downs.iter().position(|down| down.key == key) {
+                    let down = self.pending_downs.remove(pos);
+                    if event.eventTime >= down.emit_time {
+                        // The key was held for at least the threshold, but the UP arrived before
+                        // the timeout was delivered; forward the DOWN now, followed by the UP.
+                        self.next.notify_key(&down.event);
+                        self.next.notify_key(event);
+                    }
+                    // Otherwise the key was released before the threshold elapsed; the DOWN/UP
+                    // pair is dropped entirely.
+                } else {
+                    // The buffered DOWN was already forwarded when its deadline fired, so let the
+                    // matching UP through.
+                    self.next.notify_key(event);
+                }
+            }
+            _ => self.next.notify_key(event),
+        }
+    }
+
+    fn notify_key_timeout_expired(&mut self, when_nanos: i64) {
+        // Forward every held-back DOWN whose deadline has now passed, oldest first.
+        let mut i = 0;
+        while i < self.pending_downs.len() {
+            if self.pending_downs[i].emit_time <= when_nanos {
+                let down = self.pending_downs.remove(i);
+                self.next.notify_key(&down.event);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    fn notify_motion(&mut self, event: &MotionEvent) {
+        // Slow keys only gates key presses; motion events pass straight through.
+        self.next.notify_motion(event);
+    }
+
+    fn notify_devices_changed(&mut self, device_infos: &[DeviceInfo]) {
+        // Drop any pending presses for devices that are no longer present.
+        self.pending_downs
+            .retain(|down| device_infos.iter().any(|info| info.deviceId == down.key.device_id));
+        self.next.notify_devices_changed(device_infos);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_filter::{test_callbacks::TestCallbacks, test_filter::TestFilter, Filter};
+    use crate::slow_keys_filter::SlowKeysFilter;
+    use android_hardware_input_common::aidl::android::hardware::input::common::Source::Source;
+    use binder::Strong;
+    use com_android_server_inputflinger::aidl::com::android::server::inputflinger::{
+        KeyEvent::KeyEvent, KeyEventAction::KeyEventAction,
+    };
+    use std::sync::{Arc, RwLock};
+
+    const THRESHOLD_NS: i64 = 100;
+    const KEYCODE: i32 = 10;
+    const DEVICE_ID: i32 = 1;
+
+    #[test]
+    fn test_slow_key_registers_while_held_past_threshold() {
+        let next = TestFilter::new();
+        let test_callbacks = TestCallbacks::new();
+        let mut filter = SlowKeysFilter::new(
+            Box::new(next.clone()),
+            THRESHOLD_NS,
+            Arc::new(RwLock::new(Strong::new(Box::new(test_callbacks.clone())))),
+        );
+        filter.notify_key(&create_key_event(KeyEventAction::DOWN, 0));
+        // The DOWN is held back and a timeout is scheduled at the threshold deadline.
+        assert!(next.last_event().is_none());
+        assert_eq!(test_callbacks.last_timeout(), Some(THRESHOLD_NS));
+        // When that deadline fires the DOWN is delivered even though the key is still pressed.
+        filter.notify_key_timeout_expired(THRESHOLD_NS);
+        assert_eq!(next.last_event().unwrap().action, KeyEventAction::DOWN);
+        // The subsequent UP passes straight through.
+        filter.notify_key(&create_key_event(KeyEventAction::UP, THRESHOLD_NS + 50));
+        assert_eq!(next.last_event().unwrap().action, KeyEventAction::UP);
+    }
+
+    #[test]
+    fn test_slow_key_dropped_when_released_too_soon() {
+        let next = TestFilter::new();
+        let test_callbacks = TestCallbacks::new();
+        let mut filter = SlowKeysFilter::new(
+            Box::new(next.clone()),
+            THRESHOLD_NS,
+            Arc::new(RwLock::new(Strong::new(Box::new(test_callbacks)))),
+        );
+        filter.notify_key(&create_key_event(KeyEventAction::DOWN, 0));
+        filter.notify_key(&create_key_event(KeyEventAction::UP, THRESHOLD_NS - 1));
+        // The deadline never arrives (or arrives after the UP removed the pending DOWN), so
+        // nothing is forwarded.
+        filter.notify_key_timeout_expired(THRESHOLD_NS);
+        assert!(next.last_event().is_none());
+    }
+
+    #[test]
+    fn test_repeated_down_without_up_replaces_pending_entry() {
+        let next = TestFilter::new();
+        let test_callbacks = TestCallbacks::new();
+        let mut filter = SlowKeysFilter::new(
+            Box::new(next.clone()),
+            THRESHOLD_NS,
+            Arc::new(RwLock::new(Strong::new(Box::new(test_callbacks)))),
+        );
+        filter.notify_key(&create_key_event(KeyEventAction::DOWN, 0));
+        // A second DOWN for the same key without an intervening UP replaces the first pending
+        // entry rather than queuing a second one.
+        filter.notify_key(&create_key_event(KeyEventAction::DOWN, 10));
+        // The original deadline no longer has a pending entry behind it, so it fires nothing.
+        filter.notify_key_timeout_expired(THRESHOLD_NS);
+        assert!(next.last_event().is_none());
+        // The replacement's deadline delivers exactly one DOWN, carrying the later event_time.
+        filter.notify_key_timeout_expired(THRESHOLD_NS + 10);
+        assert_eq!(next.last_event().unwrap().eventTime, 10);
+    }
+
+    fn create_key_event(action: KeyEventAction, event_time: i64) -> KeyEvent {
+        KeyEvent {
+            id: 1,
+            deviceId: DEVICE_ID,
+            downTime: 0,
+            readTime: 0,
+            eventTime: event_time,
+            source: Source::KEYBOARD,
+            displayId: 0,
+            policyFlags: 0,
+            action,
+            flags: 0,
+            keyCode: KEYCODE,
+            scanCode: 0,
+            metaState: 0,
+        }
+    }
+}